@@ -0,0 +1,30 @@
+use curl_parser::ParsedRequest;
+use std::{hint::black_box, time::Instant};
+
+const INPUT: &str = r#"curl \
+    -X PATCH \
+    -d '{"visibility":"private"}' \
+    -H "Accept: application/vnd.github+json" \
+    -H "Authorization: Bearer abcd1234" \
+    -H "X-GitHub-Api-Version: 2022-11-28" \
+    https://api.github.com/user/email/visibility"#;
+
+const ITERATIONS: u32 = 10_000;
+
+fn bench(name: &str, f: impl Fn()) {
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        f();
+    }
+    let elapsed = start.elapsed();
+    println!("{name:<12} {:?}/iter", elapsed / ITERATIONS);
+}
+
+fn main() {
+    bench("full parse", || {
+        black_box(black_box(INPUT).parse::<ParsedRequest>().unwrap());
+    });
+    bench("extract_url", || {
+        black_box(ParsedRequest::extract_url(black_box(INPUT)).unwrap());
+    });
+}
@@ -0,0 +1,14 @@
+use anyhow::Result;
+use curl_parser::ParsedRequest;
+
+fn main() -> Result<()> {
+    let input = r#"curl -X POST \
+    -H 'Content-Type: application/json' \
+    -d '{"name":"curl-parser"}' \
+    https://httpbin.org/post"#;
+    let parsed: ParsedRequest = input.parse()?;
+    let mut res = isahc::send(parsed.to_isahc())?;
+    println!("{}", res.status());
+    std::io::copy(res.body_mut(), &mut std::io::stdout())?;
+    Ok(())
+}
@@ -4,8 +4,10 @@ use snafu::Snafu;
 
 pub(crate) type Result<T, E = Error> = std::result::Result<T, E>;
 
+// variants depend on the enabled features, so matches need a wildcard arm
 #[derive(Debug, Snafu)]
 #[snafu(visibility(pub(crate)))]
+#[non_exhaustive]
 pub enum Error {
     #[snafu(display("Failed to parse input string with pest rules"))]
     ParseRule {
@@ -38,6 +40,50 @@ pub enum Error {
         source: http::header::InvalidHeaderValue,
     },
 
+    #[snafu(display("Input is {len} bytes, more than the allowed {max_len}"))]
+    InputTooLong { len: usize, max_len: usize },
+    #[snafu(display("Failed to read input"))]
+    ReadInput { source: std::io::Error },
+    #[snafu(display("Failed to read file {path}"))]
+    ReadFile {
+        path: String,
+        source: std::io::Error,
+    },
+
+    #[snafu(display("Failed to decode base64 body"))]
+    DecodeBase64 { source: base64::DecodeError },
+
+    #[snafu(display(
+        "Unsupported template context file {path}, expected .json, .toml (`toml` feature) or \
+         .yaml/.yml (`yaml` feature)"
+    ))]
+    UnsupportedContext { path: String },
+    #[snafu(display("Failed to parse template context file {path}"))]
+    ParseContext {
+        path: String,
+        source: serde_json::Error,
+    },
+    #[cfg(feature = "toml")]
+    #[snafu(display("Failed to parse TOML template context file {path}"))]
+    ParseTomlContext {
+        path: String,
+        source: toml::de::Error,
+    },
+    #[cfg(feature = "yaml")]
+    #[snafu(display("Failed to parse YAML template context file {path}"))]
+    ParseYamlContext {
+        path: String,
+        source: serde_yaml::Error,
+    },
+    #[cfg(feature = "template")]
+    #[snafu(display("Failed to serialize template context"))]
+    SerializeContext { source: serde_json::Error },
+    #[cfg(feature = "template")]
     #[snafu(display("Failed to render request template"))]
     Render { source: minijinja::Error },
+    #[cfg(feature = "template")]
+    #[snafu(display("Undefined template variable {name}"))]
+    UndefinedVariable { name: String },
+    #[snafu(display("Template rendering requires the `template` feature"))]
+    TemplateDisabled,
 }
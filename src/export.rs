@@ -0,0 +1,263 @@
+use crate::ParsedRequest;
+use http::header::{CONTENT_LENGTH, CONTENT_TYPE, HOST};
+use serde_json::{json, Map, Value};
+
+impl ParsedRequest {
+    /// Returns an equivalent PowerShell `Invoke-WebRequest` command. `Content-Type` is passed
+    /// with `-ContentType`, all other headers with `-Headers`.
+    pub fn to_powershell(&self) -> String {
+        let mut cmd = format!(
+            "Invoke-WebRequest -Uri {} -Method {}",
+            powershell_quote(&self.url.to_string()),
+            self.method
+        );
+        let headers: Vec<_> = self
+            .header_pairs()
+            .into_iter()
+            .filter(|(name, _)| name != CONTENT_TYPE.as_str())
+            .map(|(name, value)| {
+                format!("{} = {}", powershell_quote(&name), powershell_quote(&value))
+            })
+            .collect();
+        if !headers.is_empty() {
+            cmd.push_str(&format!(" -Headers @{{ {} }}", headers.join("; ")));
+        }
+        if let Some(content_type) = self.headers.get(CONTENT_TYPE) {
+            let content_type = String::from_utf8_lossy(content_type.as_bytes());
+            cmd.push_str(&format!(
+                " -ContentType {}",
+                powershell_quote(&content_type)
+            ));
+        }
+        if let Some(body) = self.full_body() {
+            cmd.push_str(&format!(" -Body {}", powershell_quote(&body)));
+        }
+        cmd
+    }
+
+    /// Returns an equivalent HTTPie (`http`) command. A JSON object body becomes `key=value`
+    /// (strings) and `key:=json` (other values) items, a form body `--form key=value` items, and
+    /// any other body is passed with `--raw`.
+    pub fn to_httpie(&self) -> String {
+        let mut args = vec![
+            "http".to_owned(),
+            self.method.to_string(),
+            shell_quote(&self.url.to_string()),
+        ];
+        let content_type = self
+            .headers
+            .get(CONTENT_TYPE)
+            .map(|v| String::from_utf8_lossy(v.as_bytes()).into_owned());
+        let json = match content_type.as_deref() {
+            Some("application/json") => {
+                self.body
+                    .last()
+                    .and_then(|body| match serde_json::from_str(body) {
+                        Ok(Value::Object(fields)) => Some(fields),
+                        _ => None,
+                    })
+            }
+            _ => None,
+        };
+        let form = content_type.as_deref() == Some("application/x-www-form-urlencoded");
+        if form && !self.body.is_empty() {
+            args.insert(1, "--form".to_owned());
+        }
+
+        for (name, value) in self.header_pairs() {
+            args.push(shell_quote(&format!("{name}:{value}")));
+        }
+        if let Some(fields) = json {
+            for (key, value) in fields {
+                args.push(shell_quote(&match value {
+                    Value::String(s) => format!("{key}={s}"),
+                    v => format!("{key}:={v}"),
+                }));
+            }
+        } else if form {
+            for (key, value) in self.form_pairs() {
+                args.push(shell_quote(&format!("{key}={value}")));
+            }
+        } else if let Some(body) = self.full_body() {
+            args.push(format!("--raw={}", shell_quote(&body)));
+        }
+        args.join(" ")
+    }
+
+    /// Returns a plain JSON description of the request for tooling:
+    /// `{"method", "url", "headers": {name: value}, "body": string or null, "insecure"}`.
+    /// Repeated headers have their values joined by `, `.
+    pub fn to_json(&self) -> Value {
+        let headers: Map<_, _> = self
+            .header_pairs()
+            .into_iter()
+            .map(|(name, value)| (name, Value::String(value)))
+            .collect();
+        json!({
+            "method": self.method.as_str(),
+            "url": self.url.to_string(),
+            "headers": headers,
+            "body": self.full_body(),
+            "insecure": self.insecure,
+        })
+    }
+
+    /// Renders the request as an HTTP/1.1 message: request line, headers, a blank line and the
+    /// body, with `\r\n` line endings. `Host` is taken from the URL and `Content-Length` from
+    /// the body unless the command sets them.
+    pub fn to_http_message(&self) -> String {
+        let target = self
+            .url
+            .path_and_query()
+            .map_or("/", |path_and_query| path_and_query.as_str());
+        let mut message = format!("{} {target} HTTP/1.1\r\n", self.method);
+        if !self.headers.contains_key(HOST) {
+            if let Some(host) = self.url.host() {
+                match self.url.port() {
+                    Some(port) => message.push_str(&format!("host: {host}:{port}\r\n")),
+                    None => message.push_str(&format!("host: {host}\r\n")),
+                }
+            }
+        }
+        for (name, value) in &self.headers {
+            let value = String::from_utf8_lossy(value.as_bytes());
+            message.push_str(&format!("{name}: {value}\r\n"));
+        }
+        let body = self.full_body().unwrap_or_default();
+        if !body.is_empty() && !self.headers.contains_key(CONTENT_LENGTH) {
+            message.push_str(&format!("content-length: {}\r\n", body.len()));
+        }
+        message.push_str("\r\n");
+        message.push_str(&body);
+        message
+    }
+
+    /// Headers as name/value pairs, with the values of repeated headers joined by `, `.
+    pub(crate) fn header_pairs(&self) -> Vec<(String, String)> {
+        self.headers
+            .keys()
+            .map(|name| {
+                let values: Vec<_> = self
+                    .headers
+                    .get_all(name)
+                    .iter()
+                    .map(|v| String::from_utf8_lossy(v.as_bytes()).into_owned())
+                    .collect();
+                (name.as_str().to_owned(), values.join(", "))
+            })
+            .collect()
+    }
+}
+
+/// Single-quotes `s` for a POSIX shell if it contains anything but safe characters, using the
+/// `'\''` idiom for literal quotes.
+pub(crate) fn shell_quote(s: &str) -> String {
+    let safe = |c: char| c.is_ascii_alphanumeric() || "-_./:=@%+,".contains(c);
+    if !s.is_empty() && s.chars().all(safe) {
+        s.to_owned()
+    } else {
+        format!("'{}'", s.replace('\'', r"'\''"))
+    }
+}
+
+/// Single-quotes `s` for PowerShell, where a literal `'` is written as `''`.
+fn powershell_quote(s: &str) -> String {
+    format!("'{}'", s.replace('\'', "''"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+
+    #[test]
+    fn to_powershell_should_work() -> Result<()> {
+        let input = r#"curl -X POST -H 'X-Name: O'\''Brien' -H 'Content-Type: application/json' -d '{"a":1}' https://example.com/items"#;
+        let parsed: ParsedRequest = input.parse()?;
+        let cmd = parsed.to_powershell();
+        assert!(cmd.starts_with("Invoke-WebRequest -Uri 'https://example.com/items' -Method POST"));
+        assert!(cmd.contains("'x-name' = 'O''Brien'"));
+        assert!(cmd.contains("'accept' = '*/*'"));
+        assert!(cmd.contains("-ContentType 'application/json'"));
+        assert!(cmd.ends_with(r#"-Body '{"a":1}'"#));
+
+        let cmd = "curl https://example.com/"
+            .parse::<ParsedRequest>()?
+            .to_powershell();
+        assert!(!cmd.contains("-Body"));
+        assert!(!cmd.contains("-ContentType"));
+        Ok(())
+    }
+
+    #[test]
+    fn to_httpie_should_work() -> Result<()> {
+        let input = r#"curl -X POST -H 'Authorization: Bearer abc' -H 'Content-Type: application/json' -d '{"name":"it'\''s","age":30,"tags":["a"]}' https://example.com/users"#;
+        let parsed: ParsedRequest = input.parse()?;
+        let cmd = parsed.to_httpie();
+        assert!(cmd.starts_with("http POST https://example.com/users "));
+        assert!(cmd.contains("'authorization:Bearer abc'"));
+        assert!(cmd.contains("content-type:application/json"));
+        assert!(cmd.contains(r"'name=it'\''s'"));
+        assert!(cmd.contains("age:=30"));
+        assert!(cmd.contains(r#"'tags:=["a"]'"#));
+
+        let cmd = "curl -d 'a=1' -d 'b=x y' https://example.com/"
+            .parse::<ParsedRequest>()?
+            .to_httpie();
+        assert!(cmd.starts_with("http --form POST https://example.com/ "));
+        assert!(cmd.ends_with("a=1 'b=x y'"));
+        Ok(())
+    }
+
+    #[test]
+    fn to_json_should_work() -> Result<()> {
+        let input = "curl -k -X PUT -H 'X-A: 1' -d a=1 https://example.com/items";
+        let parsed: ParsedRequest = input.parse()?;
+        assert_eq!(
+            parsed.to_json(),
+            json!({
+                "method": "PUT",
+                "url": "https://example.com/items",
+                "headers": {
+                    "x-a": "1",
+                    "content-type": "application/x-www-form-urlencoded",
+                    "accept": "*/*",
+                },
+                "body": "a=1",
+                "insecure": true,
+            })
+        );
+
+        let value = "curl https://example.com/"
+            .parse::<ParsedRequest>()?
+            .to_json();
+        assert_eq!(value["body"], Value::Null);
+        assert_eq!(value["insecure"], false);
+        Ok(())
+    }
+
+    #[test]
+    fn to_http_message_should_work() -> Result<()> {
+        let input = "curl -X POST -H 'X-A: 1' -d a=1 'https://example.com:8443/items?id=2'";
+        let parsed: ParsedRequest = input.parse()?;
+        assert_eq!(
+            parsed.to_http_message(),
+            "POST /items?id=2 HTTP/1.1\r\n\
+             host: example.com:8443\r\n\
+             x-a: 1\r\n\
+             content-type: application/x-www-form-urlencoded\r\n\
+             accept: */*\r\n\
+             content-length: 3\r\n\
+             \r\n\
+             a=1"
+        );
+
+        let message = "curl -H 'Host: api.internal' https://example.com"
+            .parse::<ParsedRequest>()?
+            .to_http_message();
+        assert!(message.starts_with("GET / HTTP/1.1\r\nhost: api.internal\r\n"));
+        assert!(message.ends_with("\r\n\r\n"));
+        assert!(!message.contains("example.com"));
+        Ok(())
+    }
+}
@@ -0,0 +1,100 @@
+/// Best-effort extraction of a curl command embedded in shell code, e.g. a here-doc
+/// (`$(cat <<EOF ... EOF)`) or an array assignment (`CMD=(curl ...)`). Returns `None` if no
+/// curl command is found.
+pub fn extract_curl_command(text: &str) -> Option<&str> {
+    if let Some(body) = heredoc_body(text) {
+        return find_curl(body);
+    }
+    if let Some(body) = array_body(text) {
+        return find_curl(body);
+    }
+    find_curl(text)
+}
+
+fn heredoc_body(text: &str) -> Option<&str> {
+    let rest = &text[text.find("<<")? + 2..];
+    let rest = rest.strip_prefix('-').unwrap_or(rest);
+    let line_end = rest.find('\n')?;
+    let delimiter = rest[..line_end]
+        .split_whitespace()
+        .next()?
+        .trim_matches(['\'', '"']);
+
+    let body = &rest[line_end + 1..];
+    let mut offset = 0;
+    for line in body.split_inclusive('\n') {
+        if line.trim() == delimiter {
+            return Some(&body[..offset]);
+        }
+        offset += line.len();
+    }
+    None
+}
+
+fn array_body(text: &str) -> Option<&str> {
+    let start = text.find("=(")? + 2;
+    let end = text.rfind(')')?;
+    (start < end).then(|| &text[start..end])
+}
+
+fn find_curl(text: &str) -> Option<&str> {
+    let (start, _) = text.match_indices("curl").find(|(i, _)| {
+        let before = text[..*i].chars().next_back();
+        // like the `program` rule, `curl` or `curl.exe` must end the word, e.g. not `curly`
+        let after = &text[i + 4..];
+        let after = after.strip_prefix(".exe").unwrap_or(after);
+        before.is_none_or(char::is_whitespace)
+            && after
+                .chars()
+                .next()
+                .is_none_or(|c| c.is_whitespace() || c == '\\')
+    })?;
+    Some(text[start..].trim_end())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ParsedRequest;
+    use anyhow::Result;
+
+    #[test]
+    fn extract_from_heredoc_should_work() -> Result<()> {
+        let text = r#"RESPONSE=$(cat <<'EOF'
+curl -X POST \
+  -H 'Content-Type: application/json' \
+  -d '{"a":1}' \
+  https://example.com/items
+EOF
+)"#;
+        let command = extract_curl_command(text).expect("curl command");
+        assert!(command.starts_with("curl -X POST"));
+        assert!(command.ends_with("https://example.com/items"));
+        let parsed: ParsedRequest = command.parse()?;
+        assert_eq!(parsed.url.to_string(), "https://example.com/items");
+        Ok(())
+    }
+
+    #[test]
+    fn extract_from_array_should_work() -> Result<()> {
+        let text = r#"CMD=(curl -H 'X-A: 1' https://example.com/)"#;
+        let command = extract_curl_command(text).expect("curl command");
+        assert_eq!(command, "curl -H 'X-A: 1' https://example.com/");
+        let parsed: ParsedRequest = command.parse()?;
+        assert_eq!(parsed.url.to_string(), "https://example.com/");
+
+        assert_eq!(extract_curl_command("echo hello"), None);
+        Ok(())
+    }
+
+    #[test]
+    fn extract_should_skip_words_starting_with_curl() -> Result<()> {
+        let command =
+            extract_curl_command("curly braces\ncurl https://x.com/").expect("curl command");
+        assert_eq!(command, "curl https://x.com/");
+        let command = extract_curl_command("curl.exe https://x.com/").expect("curl command");
+        assert_eq!(command, "curl.exe https://x.com/");
+        assert_eq!(extract_curl_command("curling is a sport"), None);
+        Ok(())
+    }
+}
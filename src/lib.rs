@@ -1,14 +1,278 @@
 pub(crate) mod error;
+mod export;
+mod extract;
 mod parser;
+mod visitor;
 
-use http::{HeaderMap, Method, Uri};
+use http::{HeaderMap, HeaderValue, Method, Uri, Version};
+use serde::Deserialize;
+use std::collections::HashSet;
 
 pub use error::Error;
+pub use extract::extract_curl_command;
+pub use parser::validate;
+pub use visitor::{parse_with_visitor, CurlVisitor};
 
 #[derive(Debug, Clone, Default)]
 pub struct ParsedRequest {
     pub method: Method,
     pub url: Uri,
+    /// The URL as written in the command, before encoding and `--url-query`.
+    pub raw_url: String,
     pub headers: HeaderMap,
+    /// Credentials from `-u`, `--oauth2-bearer` or an `Authorization` header, whichever comes
+    /// last. The `Authorization` header is set as well, except for `--digest`.
+    pub auth: Option<Auth>,
     pub body: Vec<String>,
+    /// Indexes of the `body` parts given by `--data-binary`, whose `@file` contents are sent as
+    /// is. curl strips newlines from the `-d @file` contents.
+    pub binary_parts: HashSet<usize>,
+    pub raw: bool,
+    /// `-k`/`--insecure`: skip TLS certificate verification.
+    pub insecure: bool,
+    pub upload_file: Option<String>,
+    pub follow_redirects: bool,
+    pub location_trusted: bool,
+    pub redirect_keep_post: HashSet<u16>,
+    pub form: Vec<FormPart>,
+    pub create_dirs: bool,
+    pub remote_name: bool,
+    pub remote_name_all: bool,
+    pub output: Option<OutputTarget>,
+    pub keepalive: Option<KeepAlive>,
+    /// Query fragments given by `--url-query`, already encoded and appended to `url`.
+    pub url_query: Vec<String>,
+    pub resume_from: Option<ResumeAt>,
+    pub dns_servers: Vec<String>,
+    pub dns_interface: Option<String>,
+    /// `--local-port <port>` or `--local-port <first>-<last>`. Informational only, reqwest
+    /// can't bind the local port.
+    pub local_port: Option<(u16, Option<u16>)>,
+    /// `--compressed`: asks for a compressed response with `Accept-Encoding`, unless the command
+    /// sets that header itself. Clients should decompress the response.
+    pub compressed: bool,
+    /// `--tr-encoding`: asks for a compressed transfer encoding (`TE: gzip`), which, unlike
+    /// `--compressed`, is undone by the transport and never changes the content.
+    pub request_compressed: bool,
+    /// Version requested by `--http1.0`, `--http1.1`, `--http2`, `--http2-prior-knowledge`,
+    /// `--http3` or `--http3-only`. See [`ParsedRequest::effective_http_version`].
+    pub http_version: Option<Version>,
+    /// `--http2-prior-knowledge`: use HTTP/2 without negotiating it first.
+    pub http2_prior_knowledge: bool,
+    /// `--unix-socket` path to connect through instead of TCP, e.g. `/var/run/docker.sock`.
+    /// Not applied by the reqwest conversion, which can't connect to Unix sockets.
+    pub unix_socket: Option<String>,
+    /// `--abstract-unix-socket` name (Linux abstract namespace). Not applied by the reqwest
+    /// conversion either.
+    pub abstract_unix_socket: Option<String>,
+    /// `-x`/`--proxy`, with the default `http` scheme added if missing.
+    pub proxy: Option<String>,
+    /// `--proxy-user user:password`.
+    pub proxy_auth: Option<(String, String)>,
+    /// `--proxy-header` values, sent to the proxy only.
+    pub proxy_headers: HeaderMap,
+    /// `--etag-save` file path.
+    pub etag_save: Option<String>,
+    /// `--etag-compare` value: a file path, or an inline etag such as `"abc"` or `W/"abc"`,
+    /// which is also sent as `If-None-Match`.
+    pub etag_compare: Option<String>,
+    /// `-b file`: a cookie file to read cookies from. Inline `-b 'name=value'` cookies go to the
+    /// `Cookie` header instead.
+    pub cookie_file: Option<String>,
+    /// `--expect100-timeout` in seconds. Informational only, reqwest doesn't wait for
+    /// `100 Continue`.
+    pub expect_100_timeout: Option<f64>,
+    /// `-z file`: the file whose modification time is the condition. A `-z` date (an HTTP-date
+    /// or `yyyymmdd`) is sent as `If-Modified-Since` (or `If-Unmodified-Since` with a leading
+    /// `-`) instead.
+    pub time_cond_file: Option<String>,
+    /// `-Z`/`--parallel`, `--parallel-max` and `--parallel-immediate`. Informational only, a
+    /// `ParsedRequest` is a single transfer.
+    pub parallel: Option<ParallelConfig>,
+    /// Positional arguments after the URL, which curl would treat as more URLs.
+    pub extra_args: Vec<String>,
+    /// The command as received, before template rendering.
+    pub raw_command: String,
+    /// Non-fatal issues found while parsing, e.g. ignored malformed headers.
+    pub warnings: Vec<String>,
+}
+
+/// Authentication of a request, see [`ParsedRequest::auth`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Auth {
+    /// `-u user:pass` or `Authorization: Basic ...`
+    Basic { user: String, pass: String },
+    /// `--oauth2-bearer token` or `Authorization: Bearer token`
+    Bearer(String),
+    /// `--digest -u user:pass`. Digest needs a challenge from the server, so no
+    /// `Authorization` header is sent up front.
+    Digest { user: String, pass: String },
+    /// Any other `Authorization` header value, e.g. `AWS4-HMAC-SHA256 ...`.
+    Raw(String),
+}
+
+/// TCP keepalive settings from `--no-keepalive` and `--keepalive-time <seconds>`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeepAlive {
+    Disabled,
+    Time(u64),
+}
+
+/// Parallel transfer settings. Any of the `--parallel*` options enables them.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ParallelConfig {
+    /// `--parallel-max <n>`: the maximum number of concurrent transfers.
+    pub max: Option<u32>,
+    /// `--parallel-immediate`: open new connections instead of waiting to multiplex.
+    pub immediate: bool,
+}
+
+/// Where `-o`/`--output` writes the response body.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OutputTarget {
+    /// `-o -`
+    Stdout,
+    File(String),
+}
+
+/// A multipart form part given by `-F name=value` or `--form-string name=value`. Multipart
+/// bodies are modeled only, they are not sent by the reqwest conversion.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormPart {
+    pub name: String,
+    pub value: FormValue,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FormValue {
+    /// `name=value`
+    Text(String),
+    /// `name=@path`, uploaded as a file
+    File(String),
+    /// `name=<path`, the file content is sent as a text field
+    FileContent(String),
+}
+
+/// Resume offset given by `-C <offset>` or `-C -`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResumeAt {
+    /// Resume at the given byte offset, sent as a `Range: bytes=<offset>-` header.
+    Offset(u64),
+    /// Let curl figure out the offset from the output file.
+    Auto,
+}
+
+/// Options controlling how [`ParsedRequest::load_with_options`] builds the request.
+#[derive(Debug, Clone)]
+pub struct LoadOptions {
+    /// Scheme used when the URL has none. Defaults to `http`, like curl.
+    pub default_scheme: String,
+    /// Method used when a body is given without `-X`. Defaults to `POST`, like curl.
+    pub default_body_method: Method,
+    /// Infer the content type of a `-d @file` body from the file extension (e.g. `.json` sets
+    /// `application/json`) when no `Content-Type` header is given. Defaults to `false`.
+    pub infer_content_type: bool,
+    /// `Accept` header added when the command sets none. Defaults to `*/*`, like curl; `None`
+    /// adds no `Accept` header.
+    pub default_accept: Option<HeaderValue>,
+    /// Like curl's `-g`, treat `[` and `]` in the URL path and query as literal characters and
+    /// percent-encode them (e.g. `?ids[]=1` becomes `?ids%5B%5D=1`). IPv6 hosts are kept as
+    /// is. Defaults to `false`, which keeps the brackets unencoded.
+    pub treat_brackets_literally: bool,
+    /// `User-Agent` header added when the command sets none, e.g. `curl/8.4.0` to replay
+    /// requests like curl does. Defaults to `None`, which adds no `User-Agent` header.
+    pub default_user_agent: Option<String>,
+    /// Overrides `-k`/`--insecure` to enforce a TLS verification policy: `Some(false)` always
+    /// verifies certificates, `Some(true)` never does. Defaults to `None`, which keeps what the
+    /// command says.
+    pub force_insecure: Option<bool>,
+    /// Read the files named by `--url-query name@file` and `--variable name@file` while
+    /// parsing. Defaults to `false`, as a command from an untrusted user could read any file;
+    /// such queries are skipped with a warning, and such variables are left undefined.
+    pub read_files: bool,
+    /// Read the environment variables named by `--variable %name`. Defaults to `false`, as a
+    /// command from an untrusted user could read any variable; `%name=default` then uses the
+    /// default and `%name` is left undefined.
+    pub read_env: bool,
+}
+
+impl Default for LoadOptions {
+    fn default() -> Self {
+        Self {
+            default_scheme: "http".into(),
+            default_body_method: Method::POST,
+            infer_content_type: false,
+            default_accept: Some(HeaderValue::from_static("*/*")),
+            treat_brackets_literally: false,
+            default_user_agent: None,
+            force_insecure: None,
+            read_files: false,
+            read_env: false,
+        }
+    }
+}
+
+/// Where a body (or a body part) comes from, so that clients can stream large payloads instead
+/// of loading them into memory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BodySource {
+    /// `-d value`
+    Inline(String),
+    /// `-d @file` or `-T file`
+    File(String),
+    /// `-d @-` or `-T -`
+    Stdin,
+    /// `-F name=value`
+    Multipart(Vec<FormPart>),
+    /// several `-d` values, at least one of them not inline, to be joined with `&`
+    Parts(Vec<BodySource>),
+}
+
+/// The body bytes returned by [`ParsedRequest::decoded_body`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodedBody {
+    pub content_type: Option<String>,
+    pub data: Vec<u8>,
+}
+
+/// The GraphQL payload of a request, see [`ParsedRequest::graphql`].
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GraphQlRequest {
+    pub query: String,
+    pub operation_name: Option<String>,
+    pub variables: Option<serde_json::Value>,
+}
+
+/// A canonical view of a [`ParsedRequest`] used for equality checks: headers are sorted by
+/// name and value so that insertion order doesn't matter.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NormalizedRequest {
+    pub method: Method,
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<String>,
+    pub form: Vec<FormPart>,
+    pub upload_file: Option<String>,
+}
+
+/// Differences between two requests, as returned by [`ParsedRequest::diff`]. Changes are
+/// reported as `(old, new)`; headers with several values compare their values joined by `, `.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RequestDiff {
+    pub method: Option<(Method, Method)>,
+    pub url: Option<(String, String)>,
+    pub added_headers: Vec<(String, String)>,
+    pub removed_headers: Vec<(String, String)>,
+    pub changed_headers: Vec<(String, String, String)>,
+    pub body: Option<(Vec<String>, Vec<String>)>,
+    pub form: Option<(Vec<FormPart>, Vec<FormPart>)>,
+    pub upload_file: Option<(Option<String>, Option<String>)>,
+}
+
+impl RequestDiff {
+    /// Returns `true` if the requests are equivalent.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
 }
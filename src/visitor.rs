@@ -0,0 +1,99 @@
+use crate::{
+    error::*,
+    parser::{tokenize, unquote, Rule},
+};
+
+/// Receives the recognized options of a curl command in command line order. All methods do
+/// nothing by default, so implementors only override what they need.
+pub trait CurlVisitor {
+    fn url(&mut self, _url: &str) {}
+    fn method(&mut self, _method: &str) {}
+    /// A raw `-H` value, e.g. `Accept: application/json`.
+    fn header(&mut self, _header: &str) {}
+    /// A `-d`, `--data-ascii` or `--data-binary` value as written.
+    fn body(&mut self, _body: &str) {}
+    /// `--next` or `-:`, starting a new request.
+    fn next(&mut self) {}
+    /// Any other option. `name` is the name of the grammar rule (e.g. `upload_file`), `value`
+    /// is the option argument, or the flag itself as written for options without one.
+    fn option(&mut self, _name: &str, _value: &str) {}
+}
+
+/// Parses `input` (without template rendering) and reports every recognized option to
+/// `visitor`, so that callers can build their own representation of the request.
+pub fn parse_with_visitor(input: &str, visitor: &mut impl CurlVisitor) -> Result<()> {
+    for pair in tokenize(input)? {
+        let rule = pair.as_rule();
+        let value = match pair.clone().into_inner().next() {
+            Some(inner) => unquote(inner),
+            None => pair.as_str().into(),
+        };
+        match rule {
+            Rule::url => visitor.url(&value),
+            Rule::method => visitor.method(&value),
+            Rule::header => visitor.header(&value),
+            Rule::body | Rule::data_binary => visitor.body(&value),
+            Rule::next => visitor.next(),
+            Rule::EOI => break,
+            _ => visitor.option(&format!("{rule:?}"), &value),
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+
+    #[derive(Default)]
+    struct Recorder(Vec<String>);
+
+    impl CurlVisitor for Recorder {
+        fn url(&mut self, url: &str) {
+            self.0.push(format!("url {url}"));
+        }
+
+        fn method(&mut self, method: &str) {
+            self.0.push(format!("method {method}"));
+        }
+
+        fn header(&mut self, header: &str) {
+            self.0.push(format!("header {header}"));
+        }
+
+        fn body(&mut self, body: &str) {
+            self.0.push(format!("body {body}"));
+        }
+
+        fn next(&mut self) {
+            self.0.push("next".into());
+        }
+
+        fn option(&mut self, name: &str, value: &str) {
+            self.0.push(format!("{name} {value}"));
+        }
+    }
+
+    #[test]
+    fn parse_with_visitor_should_work() -> Result<()> {
+        let input = r#"curl -X POST -H 'Accept: application/json' -d 'a=1' -L \
+          -T ./file.txt https://example.com/ --next example.org"#;
+        let mut recorder = Recorder::default();
+        parse_with_visitor(input, &mut recorder)?;
+        assert_eq!(
+            recorder.0,
+            vec![
+                "method POST",
+                "header Accept: application/json",
+                "body a=1",
+                "location -L",
+                "upload_file ./file.txt",
+                "url https://example.com/",
+                "next",
+                "url example.org",
+            ]
+        );
+        Ok(())
+    }
+}